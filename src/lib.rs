@@ -1,14 +1,3 @@
-macro_rules! vector {
-    ( $name:ident<$T:ident>($inner:ty); ) => {
-        #[derive(Clone, Copy, Debug, PartialEq)]
-        pub struct $name<$T>($inner);
-    };
-}
-
-vector! {
-    Vector3<T>([T; 3]);
-}
-
 pub trait AdditiveIdentity {
     type Output;
     fn additive_identity() -> Self::Output;
@@ -19,21 +8,64 @@ pub trait MultiplicativeIdentity {
     fn multiplicative_identity() -> Self::Output;
 }
 
-impl AdditiveIdentity for usize {
-    type Output = usize;
-    fn additive_identity() -> Self::Output {
-        0
-    }
+/// Implements `AdditiveIdentity`/`MultiplicativeIdentity` for a primitive
+/// numeric type, given its `0` and `1` literals (floats need `0.0`/`1.0`,
+/// so the literals are passed in rather than assumed).
+macro_rules! impl_identities {
+    ($($ty:ty => $zero:expr, $one:expr);* $(;)?) => {
+        $(
+            impl AdditiveIdentity for $ty {
+                type Output = $ty;
+                fn additive_identity() -> Self::Output {
+                    $zero
+                }
+            }
+
+            impl MultiplicativeIdentity for $ty {
+                type Output = $ty;
+                fn multiplicative_identity() -> Self::Output {
+                    $one
+                }
+            }
+        )*
+    };
 }
 
-impl MultiplicativeIdentity for usize {
-    type Output = usize;
-    fn multiplicative_identity() -> Self::Output {
-        1
-    }
+impl_identities! {
+    usize => 0, 1;
+    isize => 0, 1;
+    u8 => 0, 1;
+    u16 => 0, 1;
+    u32 => 0, 1;
+    u64 => 0, 1;
+    u128 => 0, 1;
+    i8 => 0, 1;
+    i16 => 0, 1;
+    i32 => 0, 1;
+    i64 => 0, 1;
+    i128 => 0, 1;
+    f32 => 0.0, 1.0;
+    f64 => 0.0, 1.0;
 }
 
+/// A fixed-size vector of `N` elements of type `T`.
+///
+/// This single const-generic type backs every dimension: `Vector3`/`Vector4`
+/// below are just aliases over it, so there is no per-arity type to declare
+/// before a new space can use it.
+///
+/// With the `serde` feature enabled, a `Vector` (de)serializes as a plain
+/// JSON array of its elements, since it is a single-field tuple struct.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Vector<T, const N: usize>(pub [T; N]);
+
+pub type Vector3<T> = Vector<T, 3>;
+pub type Vector4<T> = Vector<T, 4>;
+
 pub mod vector {
+    use crate::{AdditiveIdentity, Vector};
+    use num::Num;
+
     pub trait VectorSpace {
         type Scalar;
         type Vector;
@@ -60,93 +92,467 @@ pub mod vector {
         type Vector;
         fn vscale_mut(&self, vector: &mut Self::Vector, scalar: &Self::Scalar);
     }
-}
 
-use vector::*;
+    /// Shared elementwise folds used by both the `VAdd`/`VScale` space
+    /// operations and the `std::ops` overloads on `Vector` directly, so the
+    /// arithmetic itself is only written once.
+    pub(crate) fn elementwise<T: Copy, const N: usize>(
+        lhs: &mut [T; N],
+        rhs: [T; N],
+        f: impl Fn(T, T) -> T,
+    ) {
+        lhs.iter_mut().zip(rhs).for_each(|(l, r)| *l = f(*l, r));
+    }
 
-macro_rules! vector_space_inner {
-    (@VScale $space:ident) => {
-        impl VScale for $space {
-            type Vector = <$space as VectorSpace>::Vector;
-            type Scalar = <$space as VectorSpace>::Scalar;
-            fn vscale(&self, vector: &Self::Vector, scalar: &Self::Scalar) -> Self::Vector {
-                let mut buf = *vector;
-                self.vscale_mut(&mut buf, scalar);
-                buf
-            }
+    pub(crate) fn scale<T: Copy, const N: usize>(v: &mut [T; N], scalar: T, f: impl Fn(T, T) -> T) {
+        v.iter_mut().for_each(|val| *val = f(*val, scalar));
+    }
+
+    impl<S, T, const N: usize> VAddMut for S
+    where
+        S: VectorSpace<Vector = Vector<T, N>>,
+        T: Num + Copy,
+    {
+        type Vector = Vector<T, N>;
+        fn vadd_mut(&self, lhs: &mut Self::Vector, rhs: &Self::Vector) {
+            elementwise(&mut lhs.0, rhs.0, |l, r| l + r);
         }
-    };
+    }
 
-    (@VScaleMut $space:ident) => {
-        impl VScaleMut for $space {
-            type Vector = <$space as VectorSpace>::Vector;
-            type Scalar = <$space as VectorSpace>::Scalar;
-            fn vscale_mut(&self, vector: &mut Self::Vector, scalar: &Self::Scalar) {
-                use std::ops::MulAssign;
-                vector.0.iter_mut().for_each(|val| val.mul_assign(scalar))
-            }
+    impl<S, T, const N: usize> VAdd for S
+    where
+        S: VAddMut<Vector = Vector<T, N>>,
+        T: Copy,
+    {
+        type Vector = Vector<T, N>;
+        fn vadd(&self, lhs: &Self::Vector, rhs: &Self::Vector) -> Self::Vector {
+            let mut temp = *lhs;
+            self.vadd_mut(&mut temp, rhs);
+            temp
         }
-    };
+    }
 
-    (@VAdd $space:ident) => {
-        impl VAdd for $space {
-            type Vector = <$space as VectorSpace>::Vector;
-            fn vadd(&self, lhs: &Self::Vector, rhs: &Self::Vector) -> Self::Vector {
-                let mut temp = *lhs;
-                self.vadd_mut(&mut temp, rhs);
-                temp
-            }
+    impl<S, T, const N: usize> VScaleMut for S
+    where
+        S: VectorSpace<Vector = Vector<T, N>, Scalar = T>,
+        T: Num + Copy,
+    {
+        type Vector = Vector<T, N>;
+        type Scalar = T;
+        fn vscale_mut(&self, vector: &mut Self::Vector, scalar: &Self::Scalar) {
+            scale(&mut vector.0, *scalar, |l, s| l * s);
         }
-    };
+    }
 
-    (@VAddMut $space:ident) => {
-        impl VAddMut for $space {
-            type Vector = <$space as VectorSpace>::Vector;
-            fn vadd_mut(&self, lhs: &mut Self::Vector, rhs: &Self::Vector) {
-                use std::ops::AddAssign;
-                lhs.0
-                    .iter_mut()
-                    .zip(rhs.0)
-                    .for_each(|(l, r)| l.add_assign(r))
-            }
+    impl<S, T, const N: usize> VScale for S
+    where
+        S: VScaleMut<Vector = Vector<T, N>, Scalar = T>,
+        T: Copy,
+    {
+        type Vector = Vector<T, N>;
+        type Scalar = T;
+        fn vscale(&self, vector: &Self::Vector, scalar: &Self::Scalar) -> Self::Vector {
+            let mut buf = *vector;
+            self.vscale_mut(&mut buf, scalar);
+            buf
         }
-    };
-}
+    }
 
-macro_rules! vector_space_expand {
-    ( $($trait:ident, $space:ident),* ) => {
-        $(
-            vector_space_inner! { @$trait $space }
-        )*
-    };
+    pub trait VDot {
+        type Scalar;
+        type Vector;
+        fn vdot(&self, u: &Self::Vector, v: &Self::Vector) -> Self::Scalar;
+    }
+
+    /// The cross product, defined only for 3-element vectors.
+    pub trait VCross {
+        type Vector;
+        fn vcross(&self, u: &Self::Vector, v: &Self::Vector) -> Self::Vector;
+    }
+
+    pub(crate) fn dot<T, const N: usize>(u: &[T; N], v: &[T; N]) -> T
+    where
+        T: Num + Copy + AdditiveIdentity<Output = T>,
+    {
+        u.iter()
+            .zip(v)
+            .fold(T::additive_identity(), |acc, (l, r)| acc + *l * *r)
+    }
+
+    impl<S, T, const N: usize> VDot for S
+    where
+        S: VectorSpace<Vector = Vector<T, N>, Scalar = T>,
+        T: Num + Copy + AdditiveIdentity<Output = T>,
+    {
+        type Scalar = T;
+        type Vector = Vector<T, N>;
+        fn vdot(&self, u: &Self::Vector, v: &Self::Vector) -> T {
+            dot(&u.0, &v.0)
+        }
+    }
+
+    impl<S, T> VCross for S
+    where
+        S: VectorSpace<Vector = Vector<T, 3>, Scalar = T>,
+        T: Num + Copy,
+    {
+        type Vector = Vector<T, 3>;
+        fn vcross(&self, u: &Self::Vector, v: &Self::Vector) -> Self::Vector {
+            Vector([
+                u.0[1] * v.0[2] - u.0[2] * v.0[1],
+                u.0[2] * v.0[0] - u.0[0] * v.0[2],
+                u.0[0] * v.0[1] - u.0[1] * v.0[0],
+            ])
+        }
+    }
+
+    /// `magnitude`/`normalize` don't need a `VectorSpace`, since a float
+    /// vector can measure its own length; both are built on top of `dot`.
+    impl<T: num::Float + AdditiveIdentity<Output = T>, const N: usize> Vector<T, N> {
+        pub fn magnitude(&self) -> T {
+            dot(&self.0, &self.0).sqrt()
+        }
+
+        pub fn normalize(&self) -> Self {
+            let mag = self.magnitude();
+            let mut out = *self;
+            out.0.iter_mut().for_each(|v| *v = *v / mag);
+            out
+        }
+    }
 }
 
+use vector::*;
+
+/// Declares a marker type for a vector space over `$scalar` of dimension `$dim`.
+///
+/// `VAdd`/`VAddMut`/`VScale`/`VScaleMut` come for free from the blanket impls
+/// in the `vector` module, so this only has to wire up `VectorSpace` itself.
 macro_rules! vector_space {
-    ($space:ident, $vector:ident, $scalar:ty) => {
+    ($space:ident, $scalar:ty, $dim:expr) => {
         pub struct $space;
         impl VectorSpace for $space {
             type Scalar = $scalar;
-            type Vector = $vector<$scalar>;
-        }
-        vector_space_expand! {
-            VScaleMut, $space,
-            VScale, $space,
-            VAddMut, $space,
-            VAdd, $space
+            type Vector = Vector<$scalar, $dim>;
         }
     };
 }
 
-// pub struct Matrix3<Vec>([Vec; 3]);
+/// A fixed-size `R x C` matrix of type `T`, stored as `R` row vectors of
+/// length `C`. Like `Vector`, this single const-generic type backs every
+/// shape; `Matrix3X3` below is just an alias over it.
+///
+/// With the `serde` feature enabled, a `Matrix` (de)serializes as a plain
+/// JSON array of its rows, each of which is itself a JSON array.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Matrix<T, const R: usize, const C: usize>(pub [Vector<T, C>; R]);
 
-macro_rules! matrix {
-    ( $name:ident<$T:ident>($inner:ty)  ) => {
-        #[derive(Clone, Copy, Debug, PartialEq)]
-        pub struct $name<$T>($inner);
-    };
+pub type Matrix3X3<T> = Matrix<T, 3, 3>;
+
+/// `serde` support for `Vector`/`Matrix`, gated behind the `serde` feature.
+///
+/// Both types wrap a `[T; N]`-shaped array keyed by a const generic, and
+/// serde's array support only covers concrete lengths 0..=32 rather than an
+/// arbitrary `const N`, so `#[derive(Serialize, Deserialize)]` does not work
+/// here. Instead each type (de)serializes itself as a plain JSON sequence —
+/// `Vector` as an array of its elements, `Matrix` as an array of its rows
+/// (each row being the `Vector` sequence above) — by hand, via `collect_seq`
+/// and a small `Visitor`.
+#[cfg(feature = "serde")]
+mod serde_support {
+    use crate::{Matrix, Vector};
+    use serde::de::{self, SeqAccess, Visitor};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::fmt;
+    use std::marker::PhantomData;
+
+    impl<T: Serialize, const N: usize> Serialize for Vector<T, N> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_seq(&self.0)
+        }
+    }
+
+    struct VectorVisitor<T, const N: usize>(PhantomData<T>);
+
+    impl<'de, T: Deserialize<'de>, const N: usize> Visitor<'de> for VectorVisitor<T, N> {
+        type Value = Vector<T, N>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a sequence of {N} elements")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut elements = Vec::with_capacity(N);
+            while let Some(element) = seq.next_element()? {
+                elements.push(element);
+            }
+            elements
+                .try_into()
+                .map(Vector)
+                .map_err(|v: Vec<T>| de::Error::invalid_length(v.len(), &self))
+        }
+    }
+
+    impl<'de, T: Deserialize<'de>, const N: usize> Deserialize<'de> for Vector<T, N> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_seq(VectorVisitor(PhantomData))
+        }
+    }
+
+    impl<T, const R: usize, const C: usize> Serialize for Matrix<T, R, C>
+    where
+        Vector<T, C>: Serialize,
+    {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_seq(&self.0)
+        }
+    }
+
+    struct MatrixVisitor<T, const R: usize, const C: usize>(PhantomData<T>);
+
+    impl<'de, T, const R: usize, const C: usize> Visitor<'de> for MatrixVisitor<T, R, C>
+    where
+        Vector<T, C>: Deserialize<'de>,
+    {
+        type Value = Matrix<T, R, C>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a sequence of {R} rows")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut rows = Vec::with_capacity(R);
+            while let Some(row) = seq.next_element()? {
+                rows.push(row);
+            }
+            rows.try_into()
+                .map(Matrix)
+                .map_err(|rows: Vec<Vector<T, C>>| de::Error::invalid_length(rows.len(), &self))
+        }
+    }
+
+    impl<'de, T, const R: usize, const C: usize> Deserialize<'de> for Matrix<T, R, C>
+    where
+        Vector<T, C>: Deserialize<'de>,
+    {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_seq(MatrixVisitor(PhantomData))
+        }
+    }
+}
+
+/// `std::ops` overloads for `Vector`/`Matrix`, so callers can write `u + v`
+/// and `v * 2` directly instead of going through a `VectorSpace`. The actual
+/// arithmetic is the same `vector::elementwise`/`vector::scale` folds the
+/// space traits use, so there is one source of truth for both entry points.
+mod ops {
+    use crate::vector::{elementwise, scale};
+    use crate::{Matrix, Vector};
+    use num::Num;
+    use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+    /// Implements `$trait`/`$assign_trait` for `Vector<T, N>` over every
+    /// combination of owned/borrowed operands, folding elementwise via `$op`.
+    macro_rules! impl_vector_binop {
+        ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $op:tt) => {
+            impl<T: Num + Copy, const N: usize> $trait<Vector<T, N>> for Vector<T, N> {
+                type Output = Vector<T, N>;
+                fn $method(self, rhs: Vector<T, N>) -> Self::Output {
+                    let mut out = self;
+                    elementwise(&mut out.0, rhs.0, |l, r| l $op r);
+                    out
+                }
+            }
+
+            impl<T: Num + Copy, const N: usize> $trait<&Vector<T, N>> for Vector<T, N> {
+                type Output = Vector<T, N>;
+                fn $method(self, rhs: &Vector<T, N>) -> Self::Output {
+                    $trait::$method(self, *rhs)
+                }
+            }
+
+            impl<T: Num + Copy, const N: usize> $trait<Vector<T, N>> for &Vector<T, N> {
+                type Output = Vector<T, N>;
+                fn $method(self, rhs: Vector<T, N>) -> Self::Output {
+                    $trait::$method(*self, rhs)
+                }
+            }
+
+            impl<T: Num + Copy, const N: usize> $trait<&Vector<T, N>> for &Vector<T, N> {
+                type Output = Vector<T, N>;
+                fn $method(self, rhs: &Vector<T, N>) -> Self::Output {
+                    $trait::$method(*self, *rhs)
+                }
+            }
+
+            impl<T: Num + Copy, const N: usize> $assign_trait<Vector<T, N>> for Vector<T, N> {
+                fn $assign_method(&mut self, rhs: Vector<T, N>) {
+                    elementwise(&mut self.0, rhs.0, |l, r| l $op r);
+                }
+            }
+
+            impl<T: Num + Copy, const N: usize> $assign_trait<&Vector<T, N>> for Vector<T, N> {
+                fn $assign_method(&mut self, rhs: &Vector<T, N>) {
+                    $assign_trait::$assign_method(self, *rhs)
+                }
+            }
+        };
+    }
+
+    impl_vector_binop!(Add, add, AddAssign, add_assign, +);
+    impl_vector_binop!(Sub, sub, SubAssign, sub_assign, -);
+    impl_vector_binop!(Mul, mul, MulAssign, mul_assign, *);
+
+    /// Scalar `Vector * T` / `Vector *= T`, distinct from the elementwise
+    /// `Vector * Vector` above since the right-hand side is the scalar type.
+    impl<T: Num + Copy, const N: usize> Mul<T> for Vector<T, N> {
+        type Output = Vector<T, N>;
+        fn mul(self, rhs: T) -> Self::Output {
+            let mut out = self;
+            scale(&mut out.0, rhs, |l, s| l * s);
+            out
+        }
+    }
+
+    impl<T: Num + Copy, const N: usize> Mul<T> for &Vector<T, N> {
+        type Output = Vector<T, N>;
+        fn mul(self, rhs: T) -> Self::Output {
+            Mul::mul(*self, rhs)
+        }
+    }
+
+    impl<T: Num + Copy, const N: usize> MulAssign<T> for Vector<T, N> {
+        fn mul_assign(&mut self, rhs: T) {
+            scale(&mut self.0, rhs, |l, s| l * s);
+        }
+    }
+
+    impl<T: Num + Copy + Neg<Output = T>, const N: usize> Neg for Vector<T, N> {
+        type Output = Vector<T, N>;
+        fn neg(mut self) -> Self::Output {
+            self.0.iter_mut().for_each(|v| *v = -*v);
+            self
+        }
+    }
+
+    impl<T: Num + Copy + Neg<Output = T>, const N: usize> Neg for &Vector<T, N> {
+        type Output = Vector<T, N>;
+        fn neg(self) -> Self::Output {
+            Neg::neg(*self)
+        }
+    }
+
+    /// Implements `$trait`/`$assign_trait` for `Matrix<T, R, C>`, folding
+    /// elementwise per row via the same `Vector` operator just defined.
+    macro_rules! impl_matrix_binop {
+        ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident) => {
+            impl<T: Num + Copy, const R: usize, const C: usize> $trait<Matrix<T, R, C>>
+                for Matrix<T, R, C>
+            {
+                type Output = Matrix<T, R, C>;
+                fn $method(self, rhs: Matrix<T, R, C>) -> Self::Output {
+                    let mut out = self;
+                    out.0
+                        .iter_mut()
+                        .zip(rhs.0)
+                        .for_each(|(l, r)| *l = $trait::$method(*l, r));
+                    out
+                }
+            }
+
+            impl<T: Num + Copy, const R: usize, const C: usize> $trait<&Matrix<T, R, C>>
+                for Matrix<T, R, C>
+            {
+                type Output = Matrix<T, R, C>;
+                fn $method(self, rhs: &Matrix<T, R, C>) -> Self::Output {
+                    $trait::$method(self, *rhs)
+                }
+            }
+
+            impl<T: Num + Copy, const R: usize, const C: usize> $trait<Matrix<T, R, C>>
+                for &Matrix<T, R, C>
+            {
+                type Output = Matrix<T, R, C>;
+                fn $method(self, rhs: Matrix<T, R, C>) -> Self::Output {
+                    $trait::$method(*self, rhs)
+                }
+            }
+
+            impl<T: Num + Copy, const R: usize, const C: usize> $trait<&Matrix<T, R, C>>
+                for &Matrix<T, R, C>
+            {
+                type Output = Matrix<T, R, C>;
+                fn $method(self, rhs: &Matrix<T, R, C>) -> Self::Output {
+                    $trait::$method(*self, *rhs)
+                }
+            }
+
+            impl<T: Num + Copy, const R: usize, const C: usize> $assign_trait<Matrix<T, R, C>>
+                for Matrix<T, R, C>
+            {
+                fn $assign_method(&mut self, rhs: Matrix<T, R, C>) {
+                    self.0
+                        .iter_mut()
+                        .zip(rhs.0)
+                        .for_each(|(l, r)| $assign_trait::$assign_method(l, r));
+                }
+            }
+
+            impl<T: Num + Copy, const R: usize, const C: usize> $assign_trait<&Matrix<T, R, C>>
+                for Matrix<T, R, C>
+            {
+                fn $assign_method(&mut self, rhs: &Matrix<T, R, C>) {
+                    $assign_trait::$assign_method(self, *rhs)
+                }
+            }
+        };
+    }
+
+    impl_matrix_binop!(Add, add, AddAssign, add_assign);
+    impl_matrix_binop!(Sub, sub, SubAssign, sub_assign);
+
+    impl<T: Num + Copy, const R: usize, const C: usize> Mul<T> for Matrix<T, R, C> {
+        type Output = Matrix<T, R, C>;
+        fn mul(self, rhs: T) -> Self::Output {
+            let mut out = self;
+            out.0.iter_mut().for_each(|row| *row = Mul::mul(*row, rhs));
+            out
+        }
+    }
+
+    impl<T: Num + Copy, const R: usize, const C: usize> Mul<T> for &Matrix<T, R, C> {
+        type Output = Matrix<T, R, C>;
+        fn mul(self, rhs: T) -> Self::Output {
+            Mul::mul(*self, rhs)
+        }
+    }
+
+    impl<T: Num + Copy, const R: usize, const C: usize> MulAssign<T> for Matrix<T, R, C> {
+        fn mul_assign(&mut self, rhs: T) {
+            self.0.iter_mut().for_each(|row| *row *= rhs);
+        }
+    }
+
+    impl<T: Num + Copy + Neg<Output = T>, const R: usize, const C: usize> Neg for Matrix<T, R, C> {
+        type Output = Matrix<T, R, C>;
+        fn neg(mut self) -> Self::Output {
+            self.0.iter_mut().for_each(|row| *row = -*row);
+            self
+        }
+    }
+
+    impl<T: Num + Copy + Neg<Output = T>, const R: usize, const C: usize> Neg for &Matrix<T, R, C> {
+        type Output = Matrix<T, R, C>;
+        fn neg(self) -> Self::Output {
+            Neg::neg(*self)
+        }
+    }
 }
 
 pub mod matrix_ops {
+    use crate::{AdditiveIdentity, Matrix, MultiplicativeIdentity, Vector};
+    use num::Num;
+
     pub trait MAdd {
         type Matrix;
         fn madd(&self, lhs: &Self::Matrix, rhs: &Self::Matrix) -> Self::Matrix;
@@ -156,6 +562,193 @@ pub mod matrix_ops {
         type Matrix;
         fn madd_mut(&self, lhs: &mut Self::Matrix, rhs: &Self::Matrix);
     }
+
+    /// Matrix multiplication over a semiring: `self` is `R x C`, `rhs` is
+    /// `C x P`, and the shared inner dimension `C` is enforced by the type
+    /// system rather than a runtime check.
+    pub trait MMul<Rhs> {
+        type Output;
+        fn mmul(&self, rhs: &Rhs) -> Self::Output;
+    }
+
+    /// `M^k` by binary exponentiation, for square matrices only.
+    pub trait MPow {
+        fn mpow(&self, k: u64) -> Self;
+    }
+
+    /// The multiplicative identity for `N x N` matrices: `multiplicative_identity()`
+    /// on the diagonal, `additive_identity()` everywhere else.
+    pub(crate) fn identity<T, const N: usize>() -> Matrix<T, N, N>
+    where
+        T: AdditiveIdentity<Output = T> + MultiplicativeIdentity<Output = T> + Copy,
+    {
+        Matrix(std::array::from_fn(|i| {
+            Vector(std::array::from_fn(|j| {
+                if i == j {
+                    T::multiplicative_identity()
+                } else {
+                    T::additive_identity()
+                }
+            }))
+        }))
+    }
+
+    impl<T, const R: usize, const C: usize, const P: usize> MMul<Matrix<T, C, P>>
+        for Matrix<T, R, C>
+    where
+        T: Num + Copy + AdditiveIdentity<Output = T>,
+    {
+        type Output = Matrix<T, R, P>;
+        fn mmul(&self, rhs: &Matrix<T, C, P>) -> Self::Output {
+            Matrix(std::array::from_fn(|i| {
+                Vector(std::array::from_fn(|j| {
+                    (0..C).fold(T::additive_identity(), |acc, k| {
+                        acc + self.0[i].0[k] * rhs.0[k].0[j]
+                    })
+                }))
+            }))
+        }
+    }
+
+    impl<T, const N: usize> MPow for Matrix<T, N, N>
+    where
+        T: Num + Copy + AdditiveIdentity<Output = T> + MultiplicativeIdentity<Output = T>,
+    {
+        fn mpow(&self, k: u64) -> Self {
+            let mut result = identity::<T, N>();
+            let mut base = *self;
+            let mut exp = k;
+            while exp > 0 {
+                if exp & 1 == 1 {
+                    result = result.mmul(&base);
+                }
+                base = base.mmul(&base);
+                exp >>= 1;
+            }
+            result
+        }
+    }
+
+    /// The matrix with row `i` and column `j` removed.
+    ///
+    /// This returns a `Vec<Vec<T>>` rather than a smaller `Matrix` because
+    /// stable Rust cannot express an `N - 1` dimension in a const generic
+    /// without the unstable `generic_const_exprs` feature.
+    pub trait Minor {
+        type Scalar;
+        fn minor(&self, i: usize, j: usize) -> Vec<Vec<Self::Scalar>>;
+    }
+
+    /// `(-1)^(i+j) * minor(i, j).determinant()`.
+    pub trait Cofactor: Minor {
+        fn cofactor(&self, i: usize, j: usize) -> Self::Scalar;
+    }
+
+    pub trait Determinant {
+        type Scalar;
+        fn determinant(&self) -> Self::Scalar;
+    }
+
+    pub trait Inverse: Sized {
+        fn inverse(&self) -> Option<Self>;
+    }
+
+    fn minor_of<T: Copy>(rows: &[Vec<T>], i: usize, j: usize) -> Vec<Vec<T>> {
+        assert!(rows.len() >= 2, "minor is undefined for matrices smaller than 2x2");
+        rows.iter()
+            .enumerate()
+            .filter(|(r, _)| *r != i)
+            .map(|(_, row)| {
+                row.iter()
+                    .enumerate()
+                    .filter(|(c, _)| *c != j)
+                    .map(|(_, v)| *v)
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn determinant_of<T>(rows: &[Vec<T>]) -> T
+    where
+        T: Num + Copy,
+    {
+        if rows.len() == 1 {
+            return rows[0][0];
+        }
+        if rows.len() == 2 {
+            return rows[0][0] * rows[1][1] - rows[0][1] * rows[1][0];
+        }
+        let mut det = T::zero();
+        for j in 0..rows.len() {
+            let term = rows[0][j] * determinant_of(&minor_of(rows, 0, j));
+            if j % 2 == 0 {
+                det = det + term;
+            } else {
+                det = det - term;
+            }
+        }
+        det
+    }
+
+    impl<T, const N: usize> Minor for Matrix<T, N, N>
+    where
+        T: Copy,
+    {
+        type Scalar = T;
+        fn minor(&self, i: usize, j: usize) -> Vec<Vec<T>> {
+            let rows: Vec<Vec<T>> = self.0.iter().map(|r| r.0.to_vec()).collect();
+            minor_of(&rows, i, j)
+        }
+    }
+
+    impl<T, const N: usize> Cofactor for Matrix<T, N, N>
+    where
+        T: Num + Copy,
+    {
+        fn cofactor(&self, i: usize, j: usize) -> T {
+            let sign_is_negative = (i + j) % 2 == 1;
+            let minor_det = determinant_of(&self.minor(i, j));
+            if sign_is_negative {
+                T::zero() - minor_det
+            } else {
+                minor_det
+            }
+        }
+    }
+
+    impl<T, const N: usize> Determinant for Matrix<T, N, N>
+    where
+        T: Num + Copy,
+    {
+        type Scalar = T;
+        fn determinant(&self) -> T {
+            let rows: Vec<Vec<T>> = self.0.iter().map(|r| r.0.to_vec()).collect();
+            determinant_of(&rows)
+        }
+    }
+
+    impl<T, const N: usize> Inverse for Matrix<T, N, N>
+    where
+        T: Num + Copy,
+    {
+        fn inverse(&self) -> Option<Self> {
+            let det = self.determinant();
+            if det == T::zero() {
+                return None;
+            }
+            if N == 1 {
+                let inv_row = Vector(std::array::from_fn(|_| T::one() / self.0[0].0[0]));
+                return Some(Matrix(std::array::from_fn(|_| inv_row)));
+            }
+            // Adjugate is the transpose of the cofactor matrix.
+            let cofactors: Vec<Vec<T>> =
+                (0..N).map(|i| (0..N).map(|j| self.cofactor(i, j)).collect()).collect();
+            let rows = std::array::from_fn(|i| {
+                Vector(std::array::from_fn(|j| cofactors[j][i] / det))
+            });
+            Some(Matrix(rows))
+        }
+    }
 }
 
 pub use matrix_ops::*;
@@ -164,7 +757,7 @@ impl MAdd for ThreeDimSpaceV2 {
     type Matrix = Matrix3X3<u32>;
     fn madd(&self, lhs: &Self::Matrix, rhs: &Self::Matrix) -> Self::Matrix {
         let mut temp = *lhs;
-        self.madd_mut(&mut temp, &rhs);
+        self.madd_mut(&mut temp, rhs);
         temp
     }
 }
@@ -183,12 +776,8 @@ impl MAddMut for ThreeDimSpaceV2 {
     }
 }
 
-matrix! {
-    Matrix3X3<T>([Vector3<T>; 3])
-}
-
 vector_space! {
-    ThreeDimSpaceV2, Vector3, u32
+    ThreeDimSpaceV2, u32, 3
 }
 
 mod vec_tests {
@@ -196,54 +785,214 @@ mod vec_tests {
     #[test]
     fn three_dim_space() {
         use crate::vector::*;
-        use crate::{ThreeDimSpaceV2, Vector3};
+        use crate::{ThreeDimSpaceV2, Vector, Vector3};
         let space = ThreeDimSpaceV2;
-        let u = Vector3([1u32, 2u32, 3u32]);
-        let v = Vector3([3u32, 6u32, 9u32]);
+        let u: Vector3<u32> = Vector([1u32, 2u32, 3u32]);
+        let v: Vector3<u32> = Vector([3u32, 6u32, 9u32]);
         let result = space.vadd(&u, &v);
-        let expected = Vector3([4u32, 8u32, 12u32]);
+        let expected: Vector3<u32> = Vector([4u32, 8u32, 12u32]);
         assert_eq!(result, expected);
     }
 
     #[test]
     fn four_dim_space() {
         use crate::vector::*;
-        // define Vector4
-        vector! {
-            Vector4<T>([T; 4]);
-        }
-        // define 4D space and operations
+        use crate::{Vector, Vector4};
+
+        // define 4D space; Vector4 itself is just an alias, no new type needed
         vector_space! {
-            FourDimSpace, Vector4, u32
+            FourDimSpace, u32, 4
         }
 
         let space = FourDimSpace;
-        let u = Vector4([2u32, 4u32, 6u32, 8u32]);
-        let v = Vector4([3u32, 6u32, 9u32, 12u32]);
+        let u: Vector4<u32> = Vector([2u32, 4u32, 6u32, 8u32]);
+        let v: Vector4<u32> = Vector([3u32, 6u32, 9u32, 12u32]);
         let result = space.vadd(&u, &v);
-        let expected = Vector4([5, 10, 15, 20]);
+        let expected: Vector4<u32> = Vector([5, 10, 15, 20]);
         assert_eq!(result, expected);
     }
 
     #[test]
     fn matrix_add() {
         use crate::matrix_ops::*;
-        use crate::{Matrix3X3, ThreeDimSpaceV2, Vector3};
+        use crate::{Matrix, Matrix3X3, ThreeDimSpaceV2, Vector, Vector3};
         let space = ThreeDimSpaceV2;
-        let x: Matrix3X3<u32> =
-            Matrix3X3([Vector3([0, 1, 2]), Vector3([3, 4, 5]), Vector3([6, 7, 8])]);
-        let y: Matrix3X3<u32> = Matrix3X3([
-            Vector3([2, 4, 8]),
-            Vector3([16, 32, 64]),
-            Vector3([128, 256, 512]),
+        let row = |v: [u32; 3]| -> Vector3<u32> { Vector(v) };
+        let x: Matrix3X3<u32> = Matrix([row([0, 1, 2]), row([3, 4, 5]), row([6, 7, 8])]);
+        let y: Matrix3X3<u32> = Matrix([
+            row([2, 4, 8]),
+            row([16, 32, 64]),
+            row([128, 256, 512]),
         ]);
         let result = space.madd(&x, &y);
-        let expected: Matrix3X3<u32> = Matrix3X3([
-            Vector3([2, 5, 10]),
-            Vector3([19, 36, 69]),
-            Vector3([134, 263, 520]),
+        let expected: Matrix3X3<u32> = Matrix([
+            row([2, 5, 10]),
+            row([19, 36, 69]),
+            row([134, 263, 520]),
         ]);
         println!("{:?}", result);
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn vector_operator_overloads() {
+        use crate::{Vector, Vector3};
+        let u: Vector3<i64> = Vector([1, 2, 3]);
+        let v: Vector3<i64> = Vector([4, 5, 6]);
+
+        assert_eq!(u + v, Vector([5, 7, 9]));
+        let (ru, rv) = (&u, &v);
+        assert_eq!(ru + rv, Vector([5, 7, 9]));
+        assert_eq!(v - u, Vector([3, 3, 3]));
+        assert_eq!(u * 2, Vector([2, 4, 6]));
+        assert_eq!(-u, Vector([-1, -2, -3]));
+
+        let mut w = u;
+        w += v;
+        assert_eq!(w, Vector([5, 7, 9]));
+        w *= 3;
+        assert_eq!(w, Vector([15, 21, 27]));
+    }
+
+    #[test]
+    fn matrix_operator_overloads() {
+        use crate::{Matrix, Matrix3X3, Vector, Vector3};
+        let row = |v: [i64; 3]| -> Vector3<i64> { Vector(v) };
+        let x: Matrix3X3<i64> = Matrix([row([1, 2, 3]), row([4, 5, 6]), row([7, 8, 9])]);
+        let y: Matrix3X3<i64> = Matrix([row([1, 1, 1]), row([1, 1, 1]), row([1, 1, 1])]);
+
+        let sum = x + y;
+        let expected: Matrix3X3<i64> =
+            Matrix([row([2, 3, 4]), row([5, 6, 7]), row([8, 9, 10])]);
+        assert_eq!(sum, expected);
+
+        let scaled = x * 2;
+        let expected_scaled: Matrix3X3<i64> =
+            Matrix([row([2, 4, 6]), row([8, 10, 12]), row([14, 16, 18])]);
+        assert_eq!(scaled, expected_scaled);
+    }
+
+    #[test]
+    fn matrix_mul() {
+        use crate::matrix_ops::MMul;
+        use crate::{Matrix, Matrix3X3, Vector, Vector3};
+        let row = |v: [i64; 3]| -> Vector3<i64> { Vector(v) };
+        let identity: Matrix3X3<i64> =
+            Matrix([row([1, 0, 0]), row([0, 1, 0]), row([0, 0, 1])]);
+        let x: Matrix3X3<i64> = Matrix([row([1, 2, 3]), row([4, 5, 6]), row([7, 8, 9])]);
+
+        assert_eq!(x.mmul(&identity), x);
+
+        let y: Matrix3X3<i64> = Matrix([row([1, 0, 1]), row([0, 1, 1]), row([1, 1, 0])]);
+        let expected: Matrix3X3<i64> =
+            Matrix([row([4, 5, 3]), row([10, 11, 9]), row([16, 17, 15])]);
+        assert_eq!(x.mmul(&y), expected);
+    }
+
+    #[test]
+    fn matrix_pow() {
+        use crate::matrix_ops::MPow;
+        use crate::{Matrix, Matrix3X3, Vector, Vector3};
+        let row = |v: [i64; 3]| -> Vector3<i64> { Vector(v) };
+        let fib: Matrix3X3<i64> = Matrix([row([1, 1, 0]), row([1, 0, 0]), row([0, 0, 1])]);
+
+        let identity: Matrix3X3<i64> =
+            Matrix([row([1, 0, 0]), row([0, 1, 0]), row([0, 0, 1])]);
+        assert_eq!(fib.mpow(0), identity);
+
+        // [[1,1],[1,0]]^5 == [[8,5],[5,3]] in the top-left 2x2 block.
+        let result = fib.mpow(5);
+        assert_eq!(result.0[0].0[0], 8);
+        assert_eq!(result.0[0].0[1], 5);
+        assert_eq!(result.0[1].0[0], 5);
+        assert_eq!(result.0[1].0[1], 3);
+    }
+
+    #[test]
+    fn matrix_determinant() {
+        use crate::matrix_ops::Determinant;
+        use crate::{Matrix, Matrix3X3, Vector, Vector3};
+        let row = |v: [f64; 3]| -> Vector3<f64> { Vector(v) };
+        let m: Matrix3X3<f64> =
+            Matrix([row([6.0, 1.0, 1.0]), row([4.0, -2.0, 5.0]), row([2.0, 8.0, 7.0])]);
+        assert_eq!(m.determinant(), -306.0);
+
+        let two_by_two: Matrix<f64, 2, 2> = Matrix([Vector([3.0, 8.0]), Vector([4.0, 6.0])]);
+        assert_eq!(two_by_two.determinant(), -14.0);
+    }
+
+    #[test]
+    fn matrix_minor_and_cofactor() {
+        use crate::matrix_ops::{Cofactor, Minor};
+        use crate::{Matrix, Matrix3X3, Vector, Vector3};
+        let row = |v: [f64; 3]| -> Vector3<f64> { Vector(v) };
+        let m: Matrix3X3<f64> =
+            Matrix([row([1.0, 2.0, 3.0]), row([4.0, 5.0, 6.0]), row([7.0, 8.0, 10.0])]);
+
+        assert_eq!(m.minor(0, 0), vec![vec![5.0, 6.0], vec![8.0, 10.0]]);
+        assert_eq!(m.cofactor(0, 1), -(4.0 * 10.0 - 6.0 * 7.0));
+    }
+
+    #[test]
+    fn matrix_inverse() {
+        use crate::matrix_ops::Inverse;
+        use crate::{Matrix, Vector};
+        let m: Matrix<f64, 2, 2> = Matrix([Vector([4.0, 7.0]), Vector([2.0, 6.0])]);
+        let inv = m.inverse().expect("non-singular matrix has an inverse");
+        assert!((inv.0[0].0[0] - 0.6).abs() < 1e-9);
+        assert!((inv.0[0].0[1] - -0.7).abs() < 1e-9);
+        assert!((inv.0[1].0[0] - -0.2).abs() < 1e-9);
+        assert!((inv.0[1].0[1] - 0.4).abs() < 1e-9);
+
+        let singular: Matrix<f64, 2, 2> = Matrix([Vector([1.0, 2.0]), Vector([2.0, 4.0])]);
+        assert_eq!(singular.inverse(), None);
+    }
+
+    #[test]
+    fn vector_dot_and_cross() {
+        use crate::vector::*;
+        use crate::Vector;
+
+        vector_space! {
+            ThreeDimSpaceI64, i64, 3
+        }
+        let space = ThreeDimSpaceI64;
+        let u: Vector<i64, 3> = Vector([1, 2, 3]);
+        let v: Vector<i64, 3> = Vector([4, 5, 6]);
+
+        assert_eq!(space.vdot(&u, &v), 32);
+        assert_eq!(space.vcross(&u, &v), Vector([-3, 6, -3]));
+    }
+
+    #[test]
+    fn vector_magnitude_and_normalize() {
+        use crate::{Vector, Vector3};
+        let v: Vector3<f64> = Vector([3.0, 4.0, 0.0]);
+        assert!((v.magnitude() - 5.0).abs() < 1e-12);
+
+        let n = v.normalize();
+        assert!((n.magnitude() - 1.0).abs() < 1e-12);
+        assert!((n.0[0] - 0.6).abs() < 1e-12);
+        assert!((n.0[1] - 0.8).abs() < 1e-12);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn vector_and_matrix_serde_roundtrip() {
+        use crate::{Matrix, Matrix3X3, Vector, Vector3};
+        let row = |v: [i64; 3]| -> Vector3<i64> { Vector(v) };
+        let v: Vector3<i64> = Vector([1, 2, 3]);
+        let m: Matrix3X3<i64> = Matrix([row([1, 2, 3]), row([4, 5, 6]), row([7, 8, 9])]);
+
+        assert_eq!(serde_json::to_string(&v).unwrap(), "[1,2,3]");
+        assert_eq!(
+            serde_json::to_string(&m).unwrap(),
+            "[[1,2,3],[4,5,6],[7,8,9]]"
+        );
+
+        let v_back: Vector3<i64> = serde_json::from_str("[1,2,3]").unwrap();
+        assert_eq!(v_back, v);
+        let m_back: Matrix3X3<i64> = serde_json::from_str("[[1,2,3],[4,5,6],[7,8,9]]").unwrap();
+        assert_eq!(m_back, m);
+    }
 }